@@ -0,0 +1,92 @@
+//! GF(2) CRC combination: computes the CRC of `A ‖ B` given only the (finalized) CRCs of
+//! `A` and `B` and the byte length of `B`. See [`crate::Algorithm::combine`].
+//!
+//! The reflected accumulator is a linear function of the input bits over `GF(2)`:
+//! appending a zero bit to `A`'s state is itself a linear map, so appending `len_b` zero
+//! *bytes* is that same map raised to the `8 * len_b` power. Once `A`'s state has been
+//! advanced that far, XOR-ing in `B`'s state accounts for `B`'s actual (non-zero) bits,
+//! since CRCs are additive over `GF(2)`: `crc(A ‖ B) = shift(crc(A), 8*len(B)) ^ crc(B)`.
+//! That holds as written for a zero `init`; otherwise `B`'s state also carries `init`
+//! advanced over `len(B)` bytes, which [`crate::Algorithm::combine`] XORs back out.
+
+/// A 16x16 matrix over `GF(2)`, stored one column per entry: `matrix[n]` is the image of
+/// the basis vector with only bit `n` set.
+type Matrix = [u16; 16];
+
+/// The one-zero-bit shift operator for this crate's reflected `P(x) = x^16+x^12+x^5+1`,
+/// i.e. one round of the reflected LFSR in [`crate::update`] with no input bit mixed in.
+const fn shift_one_zero_bit(crc: u16) -> u16 {
+    if crc & 1 != 0 {
+        (crc >> 1) ^ 0x8408
+    } else {
+        crc >> 1
+    }
+}
+
+const fn base_matrix() -> Matrix {
+    let mut matrix = [0u16; 16];
+    let mut n = 0;
+    while n < 16 {
+        matrix[n] = shift_one_zero_bit(1u16 << n);
+        n += 1;
+    }
+    matrix
+}
+
+const BASE: Matrix = base_matrix();
+
+const fn identity() -> Matrix {
+    let mut matrix = [0u16; 16];
+    let mut n = 0;
+    while n < 16 {
+        matrix[n] = 1u16 << n;
+        n += 1;
+    }
+    matrix
+}
+
+const IDENTITY: Matrix = identity();
+
+/// Applies `matrix` to `vector`: XORs together the columns selected by `vector`'s set
+/// bits.
+const fn matrix_times(matrix: &Matrix, vector: u16) -> u16 {
+    let mut sum = 0u16;
+    let mut v = vector;
+    let mut i = 0;
+    while v != 0 {
+        if v & 1 != 0 {
+            sum ^= matrix[i];
+        }
+        v >>= 1;
+        i += 1;
+    }
+    sum
+}
+
+/// Composes two matrices: `(a . b)[n] = a(b[n])`.
+const fn matrix_mul(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut out = [0u16; 16];
+    let mut n = 0;
+    while n < 16 {
+        out[n] = matrix_times(a, b[n]);
+        n += 1;
+    }
+    out
+}
+
+/// Advances the reflected accumulator `crc` by `len_bytes` zero bytes: raises [`BASE`] to
+/// the `8 * len_bytes` power by binary exponentiation (squaring the matrix, multiplying
+/// it into an accumulator for each set bit of the shift count), then applies the result.
+pub(crate) const fn shift(crc: u16, len_bytes: usize) -> u16 {
+    let mut base = BASE;
+    let mut result = IDENTITY;
+    let mut exp = len_bytes.saturating_mul(8);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = matrix_mul(&result, &base);
+        }
+        base = matrix_mul(&base, &base);
+        exp >>= 1;
+    }
+    matrix_times(&result, crc)
+}
@@ -0,0 +1,124 @@
+//! An owned counterpart to [`crate::Digest`], for slotting a CCITT CRC into generic
+//! hashing code (`core::hash::Hasher`) and, with the `std` feature, streaming I/O
+//! (`std::io::Write`).
+//!
+//! [`crate::Digest`] borrows its [`crate::Algorithm`], which `Hasher` has no room for: a
+//! `BuildHasher` constructs hashers on demand and has nowhere to thread a lifetime
+//! through. [`OwnedDigest`] instead holds its `Algorithm` by value (algorithms are
+//! `Copy`), so it can be built, moved, and reused freely.
+
+use crate::table::{Bytewise, NoTable, Slice16};
+use crate::Algorithm;
+
+/// Like [`crate::Digest`], but owns its [`Algorithm`] instead of borrowing it, so it can
+/// implement [`core::hash::Hasher`] (and, with `std`, [`std::io::Write`]).
+#[derive(Debug, Copy, Clone)]
+pub struct OwnedDigest<I = NoTable> {
+    algorithm: Algorithm<I>,
+    value: u16,
+}
+
+impl<I> OwnedDigest<I> {
+    /// Starts a new digest from `algorithm.init()`.
+    pub const fn new(algorithm: Algorithm<I>) -> Self {
+        let value = algorithm.init();
+        OwnedDigest { algorithm, value }
+    }
+
+    /// Returns the state to `algorithm.init()`, so a single digest can be reused across
+    /// messages instead of constructing a new one each time.
+    pub const fn reset(&mut self) {
+        self.value = self.algorithm.init();
+    }
+
+    /// Finalizes the current state without consuming `self`, leaving the accumulator
+    /// free to keep accepting more bytes.
+    pub const fn finalize(&self) -> u16 {
+        self.algorithm.finalize(self.value)
+    }
+}
+
+impl OwnedDigest<NoTable> {
+    fn update(&mut self, bytes: &[u8]) {
+        self.value = self.algorithm.update(self.value, bytes);
+    }
+}
+
+impl OwnedDigest<Bytewise> {
+    const fn update(&mut self, bytes: &[u8]) {
+        self.value = crate::table::bytewise_update(self.algorithm.refin, self.value, bytes);
+    }
+}
+
+impl OwnedDigest<Slice16> {
+    const fn update(&mut self, bytes: &[u8]) {
+        self.value = crate::table::slice16_update(self.algorithm.refin, self.value, bytes);
+    }
+}
+
+impl core::hash::Hasher for OwnedDigest<NoTable> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    /// Widens the finalized 16-bit CRC to the `u64` the `Hasher` trait requires.
+    fn finish(&self) -> u64 {
+        u64::from(self.finalize())
+    }
+}
+
+impl core::hash::Hasher for OwnedDigest<Bytewise> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.finalize())
+    }
+}
+
+impl core::hash::Hasher for OwnedDigest<Slice16> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.finalize())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for OwnedDigest<NoTable> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for OwnedDigest<Bytewise> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for OwnedDigest<Slice16> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
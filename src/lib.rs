@@ -1,5 +1,10 @@
-#![no_std]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
+// `simd.rs`'s PCLMULQDQ/PMULL intrinsics need `unsafe`, and it's only compiled in under
+// `std` (runtime feature detection needs it). Keep the no_std build's guarantee that
+// unsafe code can never be (re)introduced absolute; only relax it to a lint when `std`
+// pulls `simd.rs` in.
+#![cfg_attr(not(feature = "std"), forbid(unsafe_code))]
+#![cfg_attr(feature = "std", deny(unsafe_code))]
 
 //! compute crcs using the ccitt polynomial efficiently
 //!
@@ -9,6 +14,17 @@
 //!
 //! https://users.ece.cmu.edu/~koopman/crc/c16/0x8810.txt
 
+mod combine;
+mod hash;
+#[cfg(feature = "std")]
+mod simd;
+mod table;
+
+pub use hash::OwnedDigest;
+pub use table::{Bytewise, NoTable, Slice16};
+
+use core::marker::PhantomData;
+
 /// The lowest level operation, applies a single byte of data to a given crc and returns the new
 /// crc
 ///
@@ -21,28 +37,111 @@ pub const fn update(crc: u16, data: u8) -> u16 {
     (((data as u16) << 8) | (crc >> 8)) ^ ((data >> 4) as u16) ^ ((data as u16) << 3)
 }
 
+/// `Algorithm` is generic over the table-driven strategy ([`NoTable`], [`Bytewise`] or
+/// [`Slice16`]) used by `update`/`checksum`; see `table.rs`. The marker is carried as a
+/// `PhantomData` since which strategy to use doesn't change any of the algorithm's
+/// parameters, only how they get applied.
 #[derive(Debug, Copy, Clone)]
-pub struct Algorithm {
+pub struct Algorithm<I = NoTable> {
     pub init: u16,
     pub refin: bool,
     pub refout: bool,
     pub xorout: u16,
     pub check: u16,
     pub residue: u16,
+    _impl: PhantomData<I>,
+}
+
+impl<I> Algorithm<I> {
+    const fn init(&self) -> u16 {
+        self.init.reverse_bits()
+    }
+
+    const fn finalize(&self, mut crc: u16) -> u16 {
+        if !self.refout {
+            crc = crc.reverse_bits();
+        }
+        crc ^ self.xorout
+    }
+
+    /// The inverse of [`Self::finalize`]: recovers the pre-finalize accumulator from a
+    /// finalized CRC.
+    const fn unfinalize(&self, crc: u16) -> u16 {
+        let mut crc = crc ^ self.xorout;
+        if !self.refout {
+            crc = crc.reverse_bits();
+        }
+        crc
+    }
+
+    /// Compares a pre-`xorout` remainder (e.g. a running [`Digest`]'s raw state) against
+    /// the algorithm's stored `residue`, handling the `refout` bit reversal. Shared by
+    /// [`Digest::check_residue`] and each strategy's `validate`.
+    const fn residue_matches(&self, mut crc: u16) -> bool {
+        if !self.refout {
+            crc = crc.reverse_bits();
+        }
+        crc == self.residue
+    }
+
+    /// Computes the CRC of the concatenation `A ‖ B`, given only the already-finalized
+    /// CRCs of `A` and `B` and the byte length of `B`. Lets independent workers checksum
+    /// segments of a buffer in parallel and a coordinator stitch the partial results
+    /// together without re-reading any bytes.
+    pub const fn combine(&self, crc_a: u16, crc_b: u16, len_b: usize) -> u16 {
+        let a = self.unfinalize(crc_a);
+        let b = self.unfinalize(crc_b);
+        // `b` already has `init`'s contribution over its own `len_b` bytes folded in;
+        // since it's following `a` rather than starting fresh, that contribution would
+        // otherwise be double-counted once `a` is shifted across the same span.
+        let correction = combine::shift(self.init(), len_b);
+        self.finalize(combine::shift(a, len_b) ^ b ^ correction)
+    }
+
+    /// Starts an owned digest (see [`OwnedDigest`]) for use as a [`core::hash::Hasher`]
+    /// or, with `std`, a [`std::io::Write`] sink.
+    pub const fn owned_digest(&self) -> OwnedDigest<I>
+    where
+        I: Copy,
+    {
+        OwnedDigest::new(*self)
+    }
+
+    /// Reinterprets `self` under a different table-driven strategy; the algorithm's
+    /// parameters (`init`, `refin`, ...) are unaffected, since the strategies all agree
+    /// on the result, just not on how fast they get there.
+    pub const fn with_impl<J>(&self) -> Algorithm<J> {
+        Algorithm {
+            init: self.init,
+            refin: self.refin,
+            refout: self.refout,
+            xorout: self.xorout,
+            check: self.check,
+            residue: self.residue,
+            _impl: PhantomData,
+        }
+    }
 }
 
-impl Algorithm {
+impl Algorithm<NoTable> {
+    #[cfg(not(feature = "std"))]
     pub const fn checksum(&self, bytes: &[u8]) -> u16 {
         let mut crc = self.init();
         crc = self.update(crc, bytes);
         self.finalize(crc)
     }
 
-    const fn init(&self) -> u16 {
-        self.init.reverse_bits()
+    /// Same as the `no_std` version above, but can no longer be a `const fn` since the
+    /// `std`-only SIMD backend it may dispatch to relies on runtime feature detection.
+    #[cfg(feature = "std")]
+    pub fn checksum(&self, bytes: &[u8]) -> u16 {
+        let mut crc = self.init();
+        crc = self.update(crc, bytes);
+        self.finalize(crc)
     }
 
-    const fn update(&self, mut crc: u16, bytes: &[u8]) -> u16 {
+    #[cfg(not(feature = "std"))]
+    pub(crate) const fn update(&self, mut crc: u16, bytes: &[u8]) -> u16 {
         let mut i = 0;
         if self.refin {
             while i < bytes.len() {
@@ -58,37 +157,141 @@ impl Algorithm {
         crc
     }
 
-    const fn finalize(&self, mut crc: u16) -> u16 {
-        if !self.refout {
-            crc = crc.reverse_bits();
+    /// Same as the `no_std` version above, except long `refin` buffers are first folded
+    /// through the SIMD backend in `simd.rs`, which needs `std` for runtime feature
+    /// detection. This can no longer be a `const fn`.
+    #[cfg(feature = "std")]
+    pub(crate) fn update(&self, mut crc: u16, bytes: &[u8]) -> u16 {
+        let mut bytes = bytes;
+        if self.refin {
+            if let Some((folded, consumed)) = simd::try_fold(crc, bytes) {
+                crc = folded;
+                bytes = &bytes[consumed..];
+            }
+            for &b in bytes {
+                crc = update(crc, b);
+            }
+        } else {
+            for &b in bytes {
+                crc = update(crc, b.reverse_bits());
+            }
         }
-        crc ^ self.xorout
+        crc
     }
 
-    pub const fn digest(&self) -> Digest {
+    pub const fn digest(&self) -> Digest<'_, NoTable> {
         Digest::new(self)
     }
+
+    /// Checks a received frame: `bytes` is the payload followed by its transmitted CRC
+    /// bytes (e.g. `checksum(payload).to_le_bytes()` appended for a `refin` algorithm, or
+    /// `.to_be_bytes()` for a non-reflected one). Returns `true` if the pre-`xorout`
+    /// remainder matches the algorithm's `residue`, i.e. the frame is intact.
+    #[cfg(not(feature = "std"))]
+    pub const fn validate(&self, bytes: &[u8]) -> bool {
+        let crc = self.update(self.init(), bytes);
+        self.residue_matches(crc)
+    }
+
+    /// See the `no_std` version above.
+    #[cfg(feature = "std")]
+    pub fn validate(&self, bytes: &[u8]) -> bool {
+        let crc = self.update(self.init(), bytes);
+        self.residue_matches(crc)
+    }
+}
+
+impl Algorithm<Bytewise> {
+    pub const fn checksum(&self, bytes: &[u8]) -> u16 {
+        let mut crc = self.init();
+        crc = table::bytewise_update(self.refin, crc, bytes);
+        self.finalize(crc)
+    }
+
+    pub const fn digest(&self) -> Digest<'_, Bytewise> {
+        Digest::new(self)
+    }
+
+    /// See [`Algorithm::validate`].
+    pub const fn validate(&self, bytes: &[u8]) -> bool {
+        let crc = table::bytewise_update(self.refin, self.init(), bytes);
+        self.residue_matches(crc)
+    }
+}
+
+impl Algorithm<Slice16> {
+    pub const fn checksum(&self, bytes: &[u8]) -> u16 {
+        let mut crc = self.init();
+        crc = table::slice16_update(self.refin, crc, bytes);
+        self.finalize(crc)
+    }
+
+    pub const fn digest(&self) -> Digest<'_, Slice16> {
+        Digest::new(self)
+    }
+
+    /// See [`Algorithm::validate`].
+    pub const fn validate(&self, bytes: &[u8]) -> bool {
+        let crc = table::slice16_update(self.refin, self.init(), bytes);
+        self.residue_matches(crc)
+    }
 }
 
 /// A `crc` crate like `Digest` api
 #[derive(Debug, Copy, Clone)]
-pub struct Digest<'a> {
-    algorithm: &'a Algorithm,
+pub struct Digest<'a, I = NoTable> {
+    algorithm: &'a Algorithm<I>,
     value: u16,
 }
 
-impl<'a> Digest<'a> {
-    const fn new(algorithm: &'a Algorithm) -> Self {
+impl<'a, I> Digest<'a, I> {
+    const fn new(algorithm: &'a Algorithm<I>) -> Self {
         let value = algorithm.init();
         Digest { algorithm, value }
     }
 
+    pub const fn finalize(self) -> u16 {
+        self.algorithm.finalize(self.value)
+    }
+
+    /// Returns the state to `algorithm.init()`, so a single digest can be reused across
+    /// messages instead of constructing a new one each time.
+    pub const fn reset(&mut self) {
+        self.value = self.algorithm.init();
+    }
+
+    /// Folds in `B`'s already-finalized CRC and byte length, as if `B`'s bytes had been
+    /// fed into this digest directly after whatever has been accumulated so far. See
+    /// [`Algorithm::combine`].
+    pub const fn combine(&mut self, crc_b: u16, len_b: usize) {
+        let b = self.algorithm.unfinalize(crc_b);
+        let correction = combine::shift(self.algorithm.init(), len_b);
+        self.value = combine::shift(self.value, len_b) ^ b ^ correction;
+    }
+
+    /// Checks a received frame fed in through [`Self::update`] (payload followed by its
+    /// transmitted CRC bytes): returns `true` if the pre-`xorout` remainder matches the
+    /// algorithm's `residue`. See [`Algorithm::validate`].
+    pub const fn check_residue(self) -> bool {
+        self.algorithm.residue_matches(self.value)
+    }
+}
+
+impl<'a> Digest<'a, NoTable> {
     pub fn update(&mut self, bytes: &[u8]) {
         self.value = self.algorithm.update(self.value, bytes);
     }
+}
 
-    pub const fn finalize(self) -> u16 {
-        self.algorithm.finalize(self.value)
+impl<'a> Digest<'a, Bytewise> {
+    pub const fn update(&mut self, bytes: &[u8]) {
+        self.value = table::bytewise_update(self.algorithm.refin, self.value, bytes);
+    }
+}
+
+impl<'a> Digest<'a, Slice16> {
+    pub const fn update(&mut self, bytes: &[u8]) {
+        self.value = table::slice16_update(self.algorithm.refin, self.value, bytes);
     }
 }
 
@@ -102,6 +305,7 @@ pub const CRC_16_XMODEM: Algorithm = Algorithm {
     xorout: 0,
     check: 0x31c3,
     residue: 0,
+    _impl: PhantomData,
 };
 
 /// CRC-16/GENIBUS
@@ -114,6 +318,7 @@ pub const CRC_16_GENIBUS: Algorithm = Algorithm {
     xorout: 0xffff,
     check: 0xd64e,
     residue: 0x1d0f,
+    _impl: PhantomData,
 };
 
 /// CRC-16/GSM
@@ -126,6 +331,7 @@ pub const CRC_16_GSM: Algorithm = Algorithm {
     xorout: 0xffff,
     check: 0xce3c,
     residue: 0x1d0f,
+    _impl: PhantomData,
 };
 
 /// CRC-16/IBM-3740
@@ -138,6 +344,7 @@ pub const CRC_16_IBM_3740: Algorithm = Algorithm {
     xorout: 0,
     check: 0x29b1,
     residue: 0x000,
+    _impl: PhantomData,
 };
 
 pub const CRC_16_AUTOSAR: Algorithm = CRC_16_IBM_3740;
@@ -152,6 +359,7 @@ pub const CRC_16_IBM_SDLC: Algorithm = Algorithm {
     xorout: 0xffff,
     check: 0x906e,
     residue: 0xf0b8,
+    _impl: PhantomData,
 };
 
 pub const CRC_16_ISO_HDLC: Algorithm = CRC_16_IBM_SDLC;
@@ -168,6 +376,7 @@ pub const CRC_16_ISO_IEC_14443_3_A: Algorithm = Algorithm {
     xorout: 0,
     check: 0xbf05,
     residue: 0,
+    _impl: PhantomData,
 };
 
 /// CRC-16/KERMIT
@@ -180,6 +389,7 @@ pub const CRC_16_KERMIT: Algorithm = Algorithm {
     xorout: 0,
     check: 0x2189,
     residue: 0,
+    _impl: PhantomData,
 };
 
 pub const CRC_16_CCITT: Algorithm = CRC_16_KERMIT;
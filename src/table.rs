@@ -0,0 +1,103 @@
+//! Table-driven implementation strategies, selected via `Algorithm`'s `Impl` type
+//! parameter.
+//!
+//! Mirrors the layered strategy the `crc` crate exposes: [`NoTable`] is the
+//! byte-at-a-time scheme already in `lib.rs`, [`Bytewise`] adds a single 256-entry
+//! lookup table, and [`Slice16`] consumes 16 input bytes per round using 16 such
+//! tables. All tables are generated by a `const fn` at compile time by driving
+//! [`crate::update`], the same logic the `NoTable` strategy uses directly, so building
+//! one never needs anything beyond what the crate root already provides.
+
+use crate::update;
+
+/// The byte-at-a-time strategy: no precomputed table, smallest code size. The default
+/// for [`crate::Algorithm`] and [`crate::Digest`].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NoTable;
+
+/// A single 256-entry lookup table, trading 512 bytes of `.rodata` for one table lookup
+/// per input byte instead of the shift/xor arithmetic in [`crate::update`].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Bytewise;
+
+/// 16 lookup tables of 256 entries each, consuming 16 input bytes per round.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Slice16;
+
+pub(crate) const fn bytewise_table() -> [u16; 256] {
+    let mut table = [0u16; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = update(0, i as u8);
+        i += 1;
+    }
+    table
+}
+
+pub(crate) const BYTEWISE: [u16; 256] = bytewise_table();
+
+pub(crate) const fn slice16_table() -> [[u16; 256]; 16] {
+    let mut tables = [[0u16; 256]; 16];
+    tables[0] = bytewise_table();
+    let mut k = 1;
+    while k < 16 {
+        let mut i = 0;
+        while i < 256 {
+            let prev = tables[k - 1][i];
+            tables[k][i] = BYTEWISE[(prev & 0xFF) as usize] ^ (prev >> 8);
+            i += 1;
+        }
+        k += 1;
+    }
+    tables
+}
+
+pub(crate) const SLICE16: [[u16; 256]; 16] = slice16_table();
+
+/// Advances `crc` over `bytes` one byte at a time using [`BYTEWISE`], honoring `refin`
+/// the same way [`crate::Algorithm`]'s `NoTable` loop does.
+pub(crate) const fn bytewise_update(refin: bool, mut crc: u16, bytes: &[u8]) -> u16 {
+    let mut i = 0;
+    if refin {
+        while i < bytes.len() {
+            crc = BYTEWISE[((crc ^ bytes[i] as u16) & 0xFF) as usize] ^ (crc >> 8);
+            i += 1;
+        }
+    } else {
+        while i < bytes.len() {
+            let b = bytes[i].reverse_bits();
+            crc = BYTEWISE[((crc ^ b as u16) & 0xFF) as usize] ^ (crc >> 8);
+            i += 1;
+        }
+    }
+    crc
+}
+
+/// Advances `crc` over `bytes` 16 bytes at a time using [`SLICE16`], falling back to
+/// [`bytewise_update`] for any trailing partial chunk.
+pub(crate) const fn slice16_update(refin: bool, mut crc: u16, bytes: &[u8]) -> u16 {
+    let mut i = 0;
+    while i + 16 <= bytes.len() {
+        let mut chunk = [0u8; 16];
+        let mut j = 0;
+        while j < 16 {
+            chunk[j] = if refin {
+                bytes[i + j]
+            } else {
+                bytes[i + j].reverse_bits()
+            };
+            j += 1;
+        }
+        let c0 = (chunk[0] as u16) ^ (crc & 0xFF);
+        let c1 = (chunk[1] as u16) ^ (crc >> 8);
+        let mut result = SLICE16[15][c0 as usize] ^ SLICE16[14][c1 as usize];
+        let mut k = 2;
+        while k < 16 {
+            result ^= SLICE16[15 - k][chunk[k] as usize];
+            k += 1;
+        }
+        crc = result;
+        i += 16;
+    }
+    bytewise_update(refin, crc, bytes.split_at(i).1)
+}
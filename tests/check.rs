@@ -3,6 +3,10 @@ use crc_ccitt::*;
 
 fn check(algorithm: &Algorithm) {
     assert_eq!(algorithm.checksum(b"123456789"), algorithm.check);
+    let bytewise: Algorithm<Bytewise> = algorithm.with_impl();
+    assert_eq!(bytewise.checksum(b"123456789"), bytewise.check);
+    let slice16: Algorithm<Slice16> = algorithm.with_impl();
+    assert_eq!(slice16.checksum(b"123456789"), slice16.check);
 }
 
 #[test]
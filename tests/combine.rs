@@ -0,0 +1,43 @@
+use proptest::prelude::*;
+
+fn check(algorithm: &crc_ccitt::Algorithm) {
+    proptest!(|(s: Vec<u8>, split in 0usize..1000)| {
+        let split = split.min(s.len());
+        let (a, b) = s.split_at(split);
+
+        let crc_a = algorithm.checksum(a);
+        let crc_b = algorithm.checksum(b);
+        let combined = algorithm.combine(crc_a, crc_b, b.len());
+
+        prop_assert_eq!(combined, algorithm.checksum(&s));
+    })
+}
+
+#[test]
+fn crc_16_xmodem() {
+    check(&crc_ccitt::CRC_16_XMODEM);
+}
+
+#[test]
+fn crc_16_ibm_sdlc() {
+    check(&crc_ccitt::CRC_16_IBM_SDLC);
+}
+
+#[test]
+fn crc_16_kermit() {
+    check(&crc_ccitt::CRC_16_KERMIT);
+}
+
+#[test]
+fn digest_combine_matches_algorithm_combine() {
+    let algorithm = &crc_ccitt::CRC_16_KERMIT;
+    let s = b"hello, world! this is a longer buffer to split";
+    let split = 17;
+    let (a, b) = s.split_at(split);
+
+    let mut digest = algorithm.digest();
+    digest.update(a);
+    digest.combine(algorithm.checksum(b), b.len());
+
+    assert_eq!(digest.finalize(), algorithm.checksum(s.as_slice()));
+}
@@ -0,0 +1,39 @@
+use core::hash::Hasher as _;
+use crc_ccitt::{OwnedDigest, CRC_16_KERMIT, CRC_16_XMODEM};
+
+#[test]
+fn hasher_matches_checksum() {
+    let mut hasher: OwnedDigest = CRC_16_KERMIT.owned_digest();
+    hasher.write(b"123456789");
+    assert_eq!(hasher.finish(), u64::from(CRC_16_KERMIT.checksum(b"123456789")));
+}
+
+#[test]
+fn hasher_reset_allows_reuse() {
+    let mut hasher: OwnedDigest = CRC_16_XMODEM.owned_digest();
+    hasher.write(b"123456789");
+    let first = hasher.finish();
+    hasher.reset();
+    hasher.write(b"123456789");
+    assert_eq!(hasher.finish(), first);
+}
+
+#[test]
+fn finish_does_not_consume() {
+    let mut hasher: OwnedDigest = CRC_16_XMODEM.owned_digest();
+    hasher.write(b"12345678");
+    let partial = hasher.finish();
+    hasher.write(b"9");
+    let full = hasher.finish();
+    assert_eq!(partial, u64::from(CRC_16_XMODEM.checksum(b"12345678")));
+    assert_eq!(full, u64::from(CRC_16_XMODEM.checksum(b"123456789")));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_streams_like_io_copy() {
+    let mut hasher: OwnedDigest = CRC_16_KERMIT.owned_digest();
+    let mut reader: &[u8] = b"123456789";
+    std::io::copy(&mut reader, &mut hasher).unwrap();
+    assert_eq!(hasher.finish(), u64::from(CRC_16_KERMIT.checksum(b"123456789")));
+}
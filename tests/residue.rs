@@ -0,0 +1,60 @@
+use crc_ccitt::Algorithm;
+
+fn frame(algorithm: &Algorithm, payload: &[u8]) -> Vec<u8> {
+    let crc = algorithm.checksum(payload);
+    let mut frame = payload.to_vec();
+    if algorithm.refin {
+        frame.extend_from_slice(&crc.to_le_bytes());
+    } else {
+        frame.extend_from_slice(&crc.to_be_bytes());
+    }
+    frame
+}
+
+fn check(algorithm: &Algorithm) {
+    let good = frame(algorithm, b"123456789");
+    assert!(algorithm.validate(&good));
+
+    let mut corrupted = good.clone();
+    corrupted[0] ^= 1;
+    assert!(!algorithm.validate(&corrupted));
+
+    let mut digest = algorithm.digest();
+    digest.update(&good);
+    assert!(digest.check_residue());
+}
+
+#[test]
+fn crc_16_xmodem() {
+    check(&crc_ccitt::CRC_16_XMODEM);
+}
+
+#[test]
+fn crc_16_genibus() {
+    check(&crc_ccitt::CRC_16_GENIBUS);
+}
+
+#[test]
+fn crc_16_gsm() {
+    check(&crc_ccitt::CRC_16_GSM);
+}
+
+#[test]
+fn crc_16_ibm_3740() {
+    check(&crc_ccitt::CRC_16_IBM_3740);
+}
+
+#[test]
+fn crc_16_ibm_sdlc() {
+    check(&crc_ccitt::CRC_16_IBM_SDLC);
+}
+
+#[test]
+fn crc_16_iso_iec_14443_3_a() {
+    check(&crc_ccitt::CRC_16_ISO_IEC_14443_3_A);
+}
+
+#[test]
+fn crc_16_kermit() {
+    check(&crc_ccitt::CRC_16_KERMIT);
+}